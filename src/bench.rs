@@ -0,0 +1,93 @@
+///Benchmarking support for evaluating opening-word strategies (e.g. "CRANE" vs "SLATE") by
+///auto-playing the entropy ranker against every word in a list and measuring how well it does.
+use crate::{evaluate, rank_guesses, WordList, WordleGame};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+///BenchmarkReport holds the outcome of playing every solution in a `WordList`.
+///
+/// ## Fields:
+/// 1. results: one entry per solution, `Some(guesses)` if solved within `max_steps`, else `None`
+/// 2. max_steps: the guess budget each solution was given
+pub struct BenchmarkReport {
+    pub results: Vec<Option<usize>>,
+    pub max_steps: usize,
+}
+
+impl BenchmarkReport {
+    ///The average number of guesses taken across solved games only.
+    pub fn average_guesses(&self) -> f64 {
+        let solved: Vec<usize> = self.results.iter().filter_map(|r| *r).collect();
+        if solved.is_empty() {
+            return 0.0;
+        }
+        solved.iter().sum::<usize>() as f64 / solved.len() as f64
+    }
+
+    ///The fraction of solutions that were not solved within `max_steps`.
+    pub fn failure_rate(&self) -> f64 {
+        let failures = self.results.iter().filter(|r| r.is_none()).count();
+        failures as f64 / self.results.len() as f64
+    }
+
+    ///A histogram of guess counts, mapping number of guesses to how many solutions took that many.
+    pub fn distribution(&self) -> HashMap<usize, usize> {
+        let mut dist = HashMap::new();
+        for guesses in self.results.iter().flatten() {
+            *dist.entry(*guesses).or_insert(0) += 1;
+        }
+        dist
+    }
+}
+
+///Plays out a single solution to completion using the entropy ranker to pick every guess,
+///returning the number of guesses it took, or `None` if it wasn't solved within `max_steps`.
+///
+///Unlike `WordleGame::best_guesses`, this probes only the still-possible solutions `S` as
+///candidate guesses rather than the whole `list` - `O(|S|^2)` per step instead of
+///`O(|list| * |S|)`. Run across every solution in a dictionary-sized list, the full-list variant
+///would be `O(|list|^2)` per guess step and effectively infeasible; restricting the pool to `S`
+///keeps the benchmark usable at the scale it's meant to evaluate, at the cost of never probing a
+///"non-solution" information-gathering word.
+fn solve(solution: &str, list: &WordList, num: usize, max_steps: usize) -> Option<usize> {
+    let mut game = WordleGame::init(num);
+
+    for step in 1..=max_steps {
+        let possible_solutions = game.suggest(list);
+        let guess = rank_guesses(&possible_solutions, &possible_solutions)
+            .into_iter()
+            .next()?
+            .0;
+        let result = evaluate(guess.as_str(), solution);
+        game.update(guess.as_str(), result.as_str());
+        if guess == solution {
+            return Some(step);
+        }
+    }
+
+    None
+}
+
+///Runs the entropy ranker against every word in `list` as a possible solution, in parallel, and
+///reports how many guesses it needed each time (as wordle-analyzer's bench does). Progress is
+///printed incrementally since this can take a while for large word lists.
+pub fn run(list: &WordList, num: usize, max_steps: usize) -> BenchmarkReport {
+    let total = list.words().len();
+    let done = AtomicUsize::new(0);
+
+    let results: Vec<Option<usize>> = list
+        .words()
+        .par_iter()
+        .map(|solution| {
+            let outcome = solve(solution, list, num, max_steps);
+            let completed = done.fetch_add(1, Ordering::Relaxed) + 1;
+            if completed.is_multiple_of(50) || completed == total {
+                println!("Benchmarked {}/{} solutions", completed, total);
+            }
+            outcome
+        })
+        .collect();
+
+    BenchmarkReport { results, max_steps }
+}
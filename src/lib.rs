@@ -1,4 +1,10 @@
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+
+pub mod bench;
 
 ///PresentTypes is an enumeration of the type of letter if present in a wordle game.
 ///
@@ -6,7 +12,7 @@ use std::collections::HashMap;
 /// 1. No: The letter is not at this position
 /// 2. Maybe: There is no information yet about the letter at this position
 /// 3. Yes: This letter does appear here, tho, this is not indicative that the letter cannot appear elsewhere
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 enum PresentTypes {
     No,
     Maybe,
@@ -20,6 +26,7 @@ enum PresentTypes {
 /// ## Cases:
 /// 1. Absent
 /// 2. Present: Also has a vector of positional information, See PresentTypes
+#[derive(Serialize, Deserialize)]
 enum LetterInfo {
     Absent,
     Present(Vec<PresentTypes>),
@@ -57,11 +64,11 @@ fn generate_new_vec(n: usize, p: usize, result: &GuessResult) -> Vec<PresentType
         GuessResult::Somewhere => PresentTypes::No,
         GuessResult::Yes => PresentTypes::Yes,
     };
-    return r;
+    r
 }
 
 ///For Visualisation of Vec<PresentTypes>
-fn show_vec_present_types(v: &Vec<PresentTypes>) -> String {
+fn show_vec_present_types(v: &[PresentTypes]) -> String {
     v.iter()
         .map(|x| match x {
             PresentTypes::No => "0",
@@ -81,15 +88,137 @@ fn word_to_result(word: &str) -> Vec<GuessResult> {
         .map(|x| match x {
             'y' => GuessResult::Yes,
             'm' => GuessResult::Somewhere,
-            'n' | _ => GuessResult::No,
+            _ => GuessResult::No,
         })
         .collect()
 }
 
+///Scores `guess` against `solution` the way Wordle itself would, returning the `y`/`m`/`n` string
+///that `update` consumes.
+///
+///Exact-position matches are marked `y` first and consumed from `solution`; then each remaining
+///guess letter is marked `m` if an unconsumed matching letter still exists in `solution` (which
+///is then consumed too), else `n`. This duplicate-aware two-pass approach is what makes repeated
+///letters (like the three `E`s in `EERIE`) score correctly.
+pub fn evaluate(guess: &str, solution: &str) -> String {
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let solution_chars: Vec<char> = solution.chars().collect();
+    let mut result = vec!['n'; guess_chars.len()];
+    let mut consumed = vec![false; solution_chars.len()];
+
+    for i in 0..guess_chars.len() {
+        if i < solution_chars.len() && guess_chars[i] == solution_chars[i] {
+            result[i] = 'y';
+            consumed[i] = true;
+        }
+    }
+
+    for i in 0..guess_chars.len() {
+        if result[i] == 'y' {
+            continue;
+        }
+        if let Some(j) = solution_chars
+            .iter()
+            .enumerate()
+            .position(|(j, &c)| !consumed[j] && c == guess_chars[i])
+        {
+            result[i] = 'm';
+            consumed[j] = true;
+        }
+    }
+
+    result.into_iter().collect()
+}
+
+///Ranks `candidates` by the expected information (in bits) that guessing each of them would
+///reveal, given `possible_solutions` (`S`). Shared by `WordleGame::best_guesses` (which probes a
+///whole word list) and `bench::solve` (which restricts the pool it probes to `S` itself, since
+///probing the full list at every step of every benchmarked solution is too slow to be useful).
+pub(crate) fn rank_guesses(possible_solutions: &[String], candidates: &[String]) -> Vec<(String, f64)> {
+    if possible_solutions.is_empty() {
+        return Vec::new();
+    }
+    let total = possible_solutions.len() as f64;
+    let solution_set: HashSet<&String> = possible_solutions.iter().collect();
+
+    let mut ranked: Vec<(String, f64)> = candidates
+        .iter()
+        .map(|guess| {
+            let mut buckets: HashMap<String, usize> = HashMap::new();
+            for solution in possible_solutions.iter() {
+                let pattern = evaluate(guess, solution);
+                *buckets.entry(pattern).or_insert(0) += 1;
+            }
+            let entropy: f64 = buckets
+                .values()
+                .map(|&c| {
+                    let p = c as f64 / total;
+                    -p * p.log2()
+                })
+                .sum();
+            (guess.clone(), entropy)
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| solution_set.contains(&b.0).cmp(&solution_set.contains(&a.0)))
+    });
+
+    ranked
+}
+
+///WordList holds every dictionary word of the length relevant to the current game, ready to be
+///filtered down by `WordleGame::suggest`.
+pub struct WordList {
+    words: Vec<String>,
+}
+
+impl WordList {
+    ///Loads a newline-separated word list from `path`, keeping only the words that are `num`
+    ///letters long (and lower-casing everything, since `WordleGame` works in lowercase).
+    pub fn load(path: &str, num: usize) -> io::Result<WordList> {
+        let contents = fs::read_to_string(path)?;
+        let words = contents
+            .lines()
+            .map(|w| w.trim().to_lowercase())
+            .filter(|w| w.chars().count() == num)
+            .collect();
+        Ok(WordList { words })
+    }
+
+    ///The words currently held by this list.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+///LetterCount tracks how many copies of a letter are known to be in the solution.
+///
+/// ## Fields
+/// 1. min: the minimum number of copies known to be in the solution, derived from the number of
+///    non-black (Yes/Somewhere) tiles for that letter seen in a single guess
+/// 2. max: the exact number of copies, once a black (No) tile for that letter has been seen in
+///    a guess: the number of non-black tiles for it in that guess (0 if all its tiles were black)
+#[derive(Serialize, Deserialize)]
+struct LetterCount {
+    min: usize,
+    max: Option<usize>,
+}
+
+///WordleGame holds the full state of a game in progress. It is `serde`-serializable so a game
+///can be saved to disk and resumed later (see `save`/`load`); the JSON form is simply this
+///struct's fields, keyed by name, with `information`/`letter_counts` serialized as a JSON object
+///mapping each letter (as a one-character string) to its info.
+#[derive(Serialize, Deserialize)]
 pub struct WordleGame {
     perfect_guess_so_far: Vec<char>,
     information: HashMap<char, LetterInfo>,
+    letter_counts: HashMap<char, LetterCount>,
     num: usize,
+    solution: Option<String>,
+    history: Vec<(String, String)>,
 }
 
 impl WordleGame {
@@ -98,10 +227,83 @@ impl WordleGame {
         WordleGame {
             perfect_guess_so_far: vec!['*'; num],
             information: HashMap::new(),
+            letter_counts: HashMap::new(),
             num,
+            solution: None,
+            history: Vec::new(),
         }
     }
 
+    ///Starts a game where `solution` is already known, turning the helper into a playable game:
+    ///see `play_guess`, which scores each guess against this solution instead of requiring the
+    ///result to be typed in by hand.
+    pub fn init_with_solution(num: usize, solution: &str) -> WordleGame {
+        WordleGame {
+            perfect_guess_so_far: vec!['*'; num],
+            information: HashMap::new(),
+            letter_counts: HashMap::new(),
+            num,
+            solution: Some(solution.to_lowercase()),
+            history: Vec::new(),
+        }
+    }
+
+    ///Reverts the last `n` guesses by replaying every guess before them into a fresh game state.
+    ///
+    ///This lets a mistyped `update` (or a mistyped color pattern) be corrected without having to
+    ///restart the whole game from scratch.
+    pub fn undo(&mut self, n: usize) {
+        let keep = self.history.len().saturating_sub(n);
+        let remaining = self.history[..keep].to_vec();
+
+        *self = WordleGame {
+            perfect_guess_so_far: vec!['*'; self.num],
+            information: HashMap::new(),
+            letter_counts: HashMap::new(),
+            num: self.num,
+            solution: self.solution.clone(),
+            history: Vec::new(),
+        };
+
+        for (word, result) in remaining {
+            self.update(word.as_str(), result.as_str());
+        }
+    }
+
+    ///Saves the full game state to `path` as JSON, so it can be resumed later with `load`.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json)
+    }
+
+    ///Loads a game state previously written by `save`.
+    pub fn load(path: &str) -> io::Result<WordleGame> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    ///Scores `guess` against the solution passed to `init_with_solution`, feeds the resulting
+    ///pattern into `update`, and reports whether `guess` won the game.
+    ///
+    ///Returns an error if this game was not started with `init_with_solution`.
+    pub fn play_guess(&mut self, guess: &str) -> Result<bool, String> {
+        if guess.len() != self.num {
+            return Err(format!(
+                "Number of letters are not right! (should be {})\n",
+                self.num
+            ));
+        }
+        let solution = self
+            .solution
+            .clone()
+            .ok_or_else(|| String::from("This game has no secret solution to play against"))?;
+        let guess = guess.to_lowercase();
+        let result = evaluate(guess.as_str(), solution.as_str());
+        self.update(guess.as_str(), result.as_str());
+        Ok(guess == solution)
+    }
+
     ///To check if a new word I am guessing is a wise guess
     pub fn check(&self, word: &str) -> Result<(), String> {
         if self.information.is_empty() {
@@ -124,44 +326,68 @@ impl WordleGame {
         for (correct_letter, (pos, guess_letter)) in
             self.perfect_guess_so_far.iter().zip(word.char_indices())
         {
-            if *correct_letter != '*' {
-                if *correct_letter != guess_letter {
-                    flag = true;
+            if *correct_letter != '*' && *correct_letter != guess_letter {
+                flag = true;
+                e.push_str(
+                    format!(
+                        "Error at position: {}; Expected letter `{}`, instead found `{}`\n",
+                        pos, correct_letter, guess_letter
+                    )
+                    .as_str(),
+                );
+            }
+        }
+        //? Check with the Present Somewhere Values
+        for (pos, letter) in word.char_indices() {
+            match self.information.get(&letter) {
+                Some(LetterInfo::Absent) => {
                     e.push_str(
                         format!(
-                            "Error at position: {}; Expected letter `{}`, instead found `{}`\n",
-                            pos, correct_letter, guess_letter
+                            "This letter should be absent: letter:{}, position:{}\n",
+                            letter, pos
                         )
                         .as_str(),
                     );
+                    flag = true
+                }
+                Some(LetterInfo::Present(v)) => {
+                    if matches!(v[pos], PresentTypes::No) {
+                        e.push_str(format!(
+                                "This letter has already been checked here: letter:{}, position:{}\n Extra Debug: {:?}",
+                                letter, pos, show_vec_present_types(v)
+                            ).as_str());
+                        flag = true;
+                    }
                 }
+                _ => {}
             }
         }
-        //? Check with the Present Somewhere Values
-        for (pos, letter) in word.char_indices() {
-            match self.information.get(&letter) {
-                Some(p) => match p {
-                    LetterInfo::Absent => {
-                        e.push_str(
-                            format!(
-                                "This letter should be absent: letter:{}, position:{}\n",
-                                letter, pos
-                            )
+
+        //? Check the known minimum/exact counts for repeated letters
+        let mut word_letter_counts: HashMap<char, usize> = HashMap::new();
+        for letter in word.chars() {
+            *word_letter_counts.entry(letter).or_insert(0) += 1;
+        }
+        for (letter, count) in self.letter_counts.iter() {
+            let actual = *word_letter_counts.get(letter).unwrap_or(&0);
+            if actual < count.min {
+                e.push_str(
+                    format!(
+                        "Not enough `{}`s: found {}, need at least {}\n",
+                        letter, actual, count.min
+                    )
+                    .as_str(),
+                );
+                flag = true;
+            }
+            if let Some(max) = count.max {
+                if actual > max {
+                    e.push_str(
+                        format!("Too many `{}`s: found {}, expected exactly {}\n", letter, actual, max)
                             .as_str(),
-                        );
-                        flag = true
-                    }
-                    LetterInfo::Present(v) => {
-                        if matches!(v[pos], PresentTypes::No) {
-                            e.push_str(format!(
-                                    "This letter has already been checked here: letter:{}, position:{}\n Extra Debug: {:?}",
-                                    letter, pos, show_vec_present_types(v)
-                                ).as_str());
-                            flag = true;
-                        }
-                    }
-                },
-                _ => {}
+                    );
+                    flag = true;
+                }
             }
         }
 
@@ -172,20 +398,132 @@ impl WordleGame {
         }
     }
 
+    ///Suggests every word in `list` that does not contradict the information accumulated so far.
+    ///
+    ///This reuses the same constraints `check` enforces (green positions must match
+    ///`perfect_guess_so_far`, `LetterInfo::Absent` letters must not appear, `PresentTypes::No`
+    ///positions are forbidden for that letter), plus the requirement that every letter known to
+    ///be `Present` actually shows up somewhere in the candidate.
+    pub fn suggest(&self, list: &WordList) -> Vec<String> {
+        list.words()
+            .iter()
+            .filter(|w| self.is_candidate(w))
+            .cloned()
+            .collect()
+    }
+
+    ///Ranks every word in `list` by the expected information (in bits) that guessing it would
+    ///reveal, given the still-possible solutions (`suggest(list)`).
+    ///
+    ///For each candidate guess, every still-possible solution is bucketed by the feedback
+    ///pattern `evaluate` would produce; a bucket of size `c` out of `|S|` possible solutions
+    ///contributes `-p * log2(p)` bits, where `p = c / |S|`. Guesses are returned sorted by
+    ///descending entropy, with ties broken in favour of guesses that are themselves still-valid
+    ///solutions.
+    ///
+    ///Probes every word in `list`, unlike `bench::solve` which restricts the pool to `S` (see
+    ///its doc comment for why).
+    pub fn best_guesses(&self, list: &WordList) -> Vec<(String, f64)> {
+        let possible_solutions = self.suggest(list);
+        rank_guesses(&possible_solutions, list.words())
+    }
+
+    ///Checks whether `word` is consistent with everything known so far. Unlike `check`, this
+    ///does not produce an error message and does not require any information to already exist;
+    ///it is meant to be run over a whole word list, so it stays cheap and silent.
+    fn is_candidate(&self, word: &str) -> bool {
+        if word.chars().count() != self.num {
+            return false;
+        }
+        let chars: Vec<char> = word.chars().collect();
+
+        //? Green positions must match perfect_guess_so_far
+        for (correct_letter, guess_letter) in self.perfect_guess_so_far.iter().zip(chars.iter()) {
+            if *correct_letter != '*' && correct_letter != guess_letter {
+                return false;
+            }
+        }
+
+        for (letter, info) in self.information.iter() {
+            match info {
+                LetterInfo::Absent => {
+                    if chars.contains(letter) {
+                        return false;
+                    }
+                }
+                LetterInfo::Present(v) => {
+                    if !chars.contains(letter) {
+                        return false;
+                    }
+                    for (pos, p) in v.iter().enumerate() {
+                        if matches!(p, PresentTypes::No) && chars[pos] == *letter {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        //? Respect the known minimum/exact counts for repeated letters
+        let mut word_letter_counts: HashMap<char, usize> = HashMap::new();
+        for letter in chars.iter() {
+            *word_letter_counts.entry(*letter).or_insert(0) += 1;
+        }
+        for (letter, count) in self.letter_counts.iter() {
+            let actual = *word_letter_counts.get(letter).unwrap_or(&0);
+            if actual < count.min {
+                return false;
+            }
+            if let Some(max) = count.max {
+                if actual > max {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
     ///Used to set the other letters' positional data at No, when a Yes pops up
     fn set_other_keys(&mut self, pos: usize, letter: char) {
         self.perfect_guess_so_far[pos] = letter;
         for (key, p) in self.information.iter_mut() {
             if *key != letter {
-                match p {
-                    LetterInfo::Present(v) => {
-                        v[pos] = PresentTypes::No;
-                    }
-                    _ => {}
+                if let LetterInfo::Present(v) = p {
+                    v[pos] = PresentTypes::No;
                 }
             }
         }
     }
+    ///Updates the min/exact copy counts for every distinct letter in a single guess.
+    ///
+    ///The number of non-black (Yes/Somewhere) tiles for a letter is a lower bound on how many
+    ///copies are in the solution; if that letter also has at least one black (No) tile in the
+    ///same guess, the lower bound is actually the exact count.
+    fn update_letter_counts(&mut self, new_word: &str, wordle_result: &[GuessResult]) {
+        let mut tallies: HashMap<char, (usize, usize)> = HashMap::new();
+        for (letter, result) in new_word.chars().zip(wordle_result.iter()) {
+            let tally = tallies.entry(letter).or_insert((0, 0));
+            match result {
+                GuessResult::No => tally.1 += 1,
+                GuessResult::Yes | GuessResult::Somewhere => tally.0 += 1,
+            }
+        }
+
+        for (letter, (non_black, black)) in tallies {
+            let count = self
+                .letter_counts
+                .entry(letter)
+                .or_insert(LetterCount { min: 0, max: None });
+            if non_black > count.min {
+                count.min = non_black;
+            }
+            if black > 0 {
+                count.max = Some(non_black);
+            }
+        }
+    }
+
     ///Updating the information given a new word and details
     ///
     /// ## Arguments:
@@ -200,8 +538,13 @@ impl WordleGame {
             flag.2.push(pos);
         };
 
+        self.history
+            .push((new_word.to_string(), wordle_result.to_string()));
+
         let wordle_result = word_to_result(wordle_result);
 
+        self.update_letter_counts(new_word, &wordle_result);
+
         for ((position, letter), result) in new_word.char_indices().zip(wordle_result.iter()) {
             match self.information.get_mut(&letter) {
                 None => match result {
@@ -226,19 +569,16 @@ impl WordleGame {
                         //? So, it inserts "T" absent into the hashmap
                         //? the second T is no, which triggers this condition
                         //?
-                        //? In both cases, this is either a useless guess, or it will be fixed shortly
-                        println!("Useless Guess?");
+                        //? There is nothing more to learn about positions here: update_letter_counts
+                        //? already recorded the exact/minimum copy count from this guess
                     }
                     (GuessResult::No, LetterInfo::Present(v)) => {
-                        //? Guess Result No and guess type present means that we do not have an additional one of this letter, in the word
-                        for i in 0..self.num {
-                            match v[i] {
-                                PresentTypes::Yes | PresentTypes::No => {}
-                                PresentTypes::Maybe => {
-                                    v[i] = PresentTypes::No;
-                                }
-                            }
-                        }
+                        //? Guess Result No for a letter that's already known to be present only
+                        //? rules out *this* position for that letter, not every other Maybe
+                        //? position - this letter may still appear elsewhere (e.g. one of a
+                        //? repeated letter can be gray while another copy is green/yellow).
+                        //? The overall copy count is enforced separately by letter_counts.
+                        v[position] = PresentTypes::No;
                     }
                     (GuessResult::Somewhere, LetterInfo::Absent) => {
                         //?This absolutely cannot happen i think?
@@ -276,7 +616,7 @@ impl std::fmt::Display for WordleGame {
             .filter(|(_, info)| matches!(info, LetterInfo::Absent))
             .map(|(letter, _)| format!("{}", letter))
             .fold(String::from("Absentees"), |mut acc, x| {
-                acc.push_str(",");
+                acc.push(',');
                 acc.push_str(x.as_str());
                 acc
             });
@@ -286,10 +626,10 @@ impl std::fmt::Display for WordleGame {
             .filter(|(_, info)| !matches!(info, LetterInfo::Absent))
             .map(|(letter, info)| match info {
                 LetterInfo::Present(p) => format!("{}->{}", letter, show_vec_present_types(p)),
-                LetterInfo::Absent => format!(""),
+                LetterInfo::Absent => String::new(),
             })
             .fold(String::from(""), |mut acc, x| {
-                acc.push_str("\n");
+                acc.push('\n');
                 acc.push_str(x.as_str());
                 acc
             });
@@ -301,3 +641,37 @@ impl std::fmt::Display for WordleGame {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///Regression test for a bug where a gray tile for a letter that's already known to be
+    ///present (e.g. the second `e` in "settle") wiped out every other Maybe position for that
+    ///letter, instead of just the position actually guessed - wrongly rejecting the true
+    ///solution "butter", whose single `e` sits at one of those cleared positions.
+    #[test]
+    fn repeated_letter_gray_tile_only_clobbers_its_own_position() {
+        let mut game = WordleGame::init(6);
+        let result = evaluate("settle", "butter");
+        game.update("settle", result.as_str());
+        assert!(game.check("butter").is_ok());
+    }
+
+    ///Regression test for a panic: play_guess never checked the guess length before scoring it,
+    ///so an over-length guess made evaluate assign feedback past the end of the word, which
+    ///update then indexed out of bounds on.
+    #[test]
+    fn play_guess_rejects_wrong_length_instead_of_panicking() {
+        let mut game = WordleGame::init_with_solution(5, "mango");
+        assert!(game.play_guess("zzzzzm").is_err());
+    }
+
+    ///Regression test: play_guess must lowercase the guess before scoring it against the
+    ///(already-lowercased) solution, or a differently-cased guess gets scored as all misses.
+    #[test]
+    fn play_guess_is_case_insensitive() {
+        let mut game = WordleGame::init_with_solution(5, "CRANE");
+        assert_eq!(game.play_guess("CRANE"), Ok(true));
+    }
+}
@@ -1,3 +1,4 @@
+use my_wordle::bench;
 use my_wordle::*;
 use std::io;
 
@@ -8,7 +9,7 @@ fn main() {
         io::stdin()
             .read_line(&mut input)
             .expect("Failed to read line!");
-        return String::from(input.trim());
+        String::from(input.trim())
     };
     let n: usize;
 
@@ -27,7 +28,7 @@ fn main() {
     let mut game = WordleGame::init(n);
 
     loop {
-        println!("What do you want to do?\na. Add new guess\nb. Check legality of guess\nc. Debug\nd. Exit");
+        println!("What do you want to do?\na. Add new guess\nb. Check legality of guess\nc. Debug\nd. Exit\ne. Suggest words\nf. Play against a secret word\ng. Best guesses (entropy)\nh. Undo\ni. Save game\nj. Load game\nk. Benchmark solver over a word list");
 
         match inp().as_str() {
             "a" | "A" | "1" => {
@@ -57,6 +58,127 @@ fn main() {
             "d" | "D" | "4" => {
                 break;
             }
+            "e" | "E" | "5" => {
+                println!("Enter the path to a newline-separated word list");
+                let path = inp();
+                match WordList::load(path.as_str(), n) {
+                    Ok(list) => {
+                        for word in game.suggest(&list) {
+                            println!("{}", word);
+                        }
+                    }
+                    Err(e) => {
+                        println!("Could not read word list: {}", e);
+                    }
+                }
+            }
+            "f" | "F" | "6" => {
+                println!("Enter the secret word (used only to score your guesses)");
+                let solution = inp();
+                let mut secret_game = WordleGame::init_with_solution(n, solution.as_str());
+                let mut won = false;
+
+                loop {
+                    println!("Enter your guess (or `quit` to give up)");
+                    let guess = inp();
+                    if guess.eq_ignore_ascii_case("quit") {
+                        break;
+                    }
+                    match secret_game.play_guess(guess.as_str()) {
+                        Ok(true) => {
+                            println!("You got it! The word was `{}`", solution);
+                            won = true;
+                            break;
+                        }
+                        Ok(false) => {
+                            println!("{}", secret_game);
+                        }
+                        Err(e) => {
+                            println!("{}", e);
+                            break;
+                        }
+                    }
+                }
+
+                if !won {
+                    println!("Better luck next time!");
+                }
+            }
+            "g" | "G" | "7" => {
+                println!("Enter the path to a newline-separated word list");
+                let path = inp();
+                match WordList::load(path.as_str(), n) {
+                    Ok(list) => {
+                        for (word, entropy) in game.best_guesses(&list) {
+                            println!("{} -> {:.3} bits", word, entropy);
+                        }
+                    }
+                    Err(e) => {
+                        println!("Could not read word list: {}", e);
+                    }
+                }
+            }
+            //? Labeled "h" rather than "f" since "f" was already taken by the secret-word mode
+            "h" | "H" | "8" => {
+                println!("Undo how many guesses?");
+                match inp().parse() {
+                    Ok(count) => {
+                        game.undo(count);
+                        println!("Undid the last {} guess(es)", count);
+                    }
+                    Err(e) => {
+                        println!("Not a number! {}", e);
+                    }
+                }
+            }
+            "i" | "I" | "9" => {
+                println!("Enter the path to save to");
+                let path = inp();
+                match game.save(path.as_str()) {
+                    Ok(()) => println!("Saved!"),
+                    Err(e) => println!("Could not save game: {}", e),
+                }
+            }
+            "j" | "J" | "10" => {
+                println!("Enter the path to load from");
+                let path = inp();
+                match WordleGame::load(path.as_str()) {
+                    Ok(loaded) => {
+                        game = loaded;
+                        println!("Loaded!");
+                    }
+                    Err(e) => println!("Could not load game: {}", e),
+                }
+            }
+            "k" | "K" | "11" => {
+                println!("Enter the path to a newline-separated word list");
+                let path = inp();
+                println!("Max guesses allowed per solution?");
+                let max_steps: usize = match inp().parse() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        println!("Not a number! {}", e);
+                        continue;
+                    }
+                };
+
+                match WordList::load(path.as_str(), n) {
+                    Ok(list) => {
+                        let report = bench::run(&list, n, max_steps);
+                        println!("Average guesses: {:.3}", report.average_guesses());
+                        println!("Failure rate: {:.3}%", report.failure_rate() * 100.0);
+                        let mut distribution: Vec<(usize, usize)> =
+                            report.distribution().into_iter().collect();
+                        distribution.sort_by_key(|(guesses, _)| *guesses);
+                        for (guesses, count) in distribution {
+                            println!("{} guesses: {}", guesses, count);
+                        }
+                    }
+                    Err(e) => {
+                        println!("Could not read word list: {}", e);
+                    }
+                }
+            }
             _ => {
                 println!("Invalid input!");
             }